@@ -1,16 +1,115 @@
+extern crate num;
+
+pub mod fft;
+
 use signals::periodic_signal::PeriodicSignal;
+use signals::signal::Signal;
+use self::num::complex::Complex;
+use self::fft::{fft, ifft};
 
+/**
+  Computes the autocorrelation of a periodic signal via the
+  Wiener-Khinchin theorem: the autocorrelation is the inverse FFT of
+  the power spectrum `X * conj(X)`, where `X = FFT(x)`.
+  Implemented as the cross-correlation of the signal with itself.
+*/
 pub fn autocorrelation(sig: PeriodicSignal<f64>)
   -> PeriodicSignal<f64> {
-  let mut vals: Vec<f64> = Vec::new();
-  for k in 0..sig.size() {
-    let mut val: f64 = 0.;
-    for i in 0..sig.size() {
-      val += sig.get(i as isize)*sig.get((i+k) as isize);
+  let values = sig.to_vector(0, sig.size() as isize - 1);
+  PeriodicSignal::new(fft_correlation(&values, &values))
+}
+
+/**
+  Computes the cross-correlation `r_ab[k] = (1/N) sum_i a.get(i) *
+  b.get(i+k)` of two periodic signals sharing a common period `N`,
+  via `IFFT(conj(FFT(a)) * FFT(b))`.
+*/
+pub fn crosscorrelation(a: PeriodicSignal<f64>, b: PeriodicSignal<f64>)
+  -> PeriodicSignal<f64> {
+  assert_eq!(a.size(), b.size(),
+    "crosscorrelation requires both signals to share a period");
+  let a_values = a.to_vector(0, a.size() as isize - 1);
+  let b_values = b.to_vector(0, b.size() as isize - 1);
+  PeriodicSignal::new(fft_correlation(&a_values, &b_values))
+}
+
+/**
+  Computes `r[k] = (1/N) sum_i a[i] * b[(i+k) mod N]` for two vectors
+  of equal length `N` via `IFFT(cross_spectrum(a, b))`. If `N` is a
+  power of two, this is computed directly with an N-length FFT.
+  Otherwise both vectors are zero-padded to the next power of two
+  that is at least `2N` first, which turns the FFT-based correlation into a linear
+  (non-wrapping) one; the missing wrap-around term, the *other-order*
+  correlation of `b` against `a` at lag `N-k`, is then folded back in
+  to recover the circular correlation over the original period.
+*/
+fn fft_correlation(a: &Vec<f64>, b: &Vec<f64>) -> Vec<f64> {
+  let n = a.len();
+  if n.is_power_of_two() {
+    let a: Vec<Complex<f64>> = a.iter().map(|v| Complex::new(*v, 0.)).collect();
+    let b: Vec<Complex<f64>> = b.iter().map(|v| Complex::new(*v, 0.)).collect();
+    let corr = ifft(&cross_spectrum(&a, &b));
+    corr.iter().map(|v| v.re/(n as f64)).collect()
+  } else {
+    let m = (2*n).next_power_of_two();
+    let mut a: Vec<Complex<f64>> = a.iter().map(|v| Complex::new(*v, 0.)).collect();
+    let mut b: Vec<Complex<f64>> = b.iter().map(|v| Complex::new(*v, 0.)).collect();
+    a.resize(m, Complex::new(0., 0.));
+    b.resize(m, Complex::new(0., 0.));
+    let corr_ab = ifft(&cross_spectrum(&a, &b));
+    let corr_ba = ifft(&cross_spectrum(&b, &a));
+    (0..n).map(|k| {
+      let wrapped = if k == 0 { 0. } else { corr_ba[n-k].re };
+      (corr_ab[k].re + wrapped)/(n as f64)
+    }).collect()
+  }
+}
+
+/**
+  Forms the cross-spectrum `conj(A[k]) * B[k]` of `a` and `b`.
+*/
+fn cross_spectrum(a: &Vec<Complex<f64>>, b: &Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+  fft(a).iter().zip(fft(b).iter()).map(|(x, y)| x.conj()*y).collect()
+}
+
+/**
+  Estimates order-`p` linear-prediction coefficients from an
+  autocorrelation sequence `r[0..=p]` (as produced by
+  `autocorrelation`) using the Levinson-Durbin recursion.
+  The returned coefficients use the same sign convention as
+  `ZeroPaddedSignal::linear_prediction`, i.e. they can be fed
+  straight into it to predict `x[n]` as `sum_k a[k]*x[n-1-k]`.
+  If `r[0]` is zero or the prediction error reaches zero before
+  order `p` is reached, the recursion stops early and the
+  coefficients computed so far are returned.
+*/
+pub fn levinson_durbin(r: &Vec<f64>, p: usize) -> Vec<f64> {
+  assert_eq!(r.len(), p+1,
+    "levinson_durbin requires an autocorrelation sequence r[0..=p]");
+  let mut a: Vec<f64> = Vec::with_capacity(p);
+  if r[0] == 0. {
+    return a;
+  }
+  let mut e = r[0];
+  for i in 1..(p+1) {
+    if e == 0. {
+      break;
+    }
+    /* Reflection coefficient: */
+    let mut acc = r[i];
+    for j in 1..i {
+      acc -= a[j-1]*r[i-j];
+    }
+    let k = acc/e;
+    /* Snapshot the old coefficients before updating them in place: */
+    let old = a.clone();
+    a.push(k);
+    for j in 1..i {
+      a[j-1] = old[j-1] - k*old[i-j-1];
     }
-    vals.push(val/(sig.size() as f64));
+    e *= 1. - k*k;
   }
-  PeriodicSignal::new(vals)
+  a
 }
 
 #[cfg(test)]
@@ -25,4 +124,116 @@ mod tests {
     x.set_vals(-0.58579f64,1f64);
     //assert_eq!(vec![1.], autocorrelation(PeriodicSignal::new(x.to_vector())).get_values());
   }
+
+  /* Brute-force O(N^2) autocorrelation to check the FFT-based result against: */
+  fn autocorrelation_naive(sig: &PeriodicSignal<f64>) -> Vec<f64> {
+    let mut vals: Vec<f64> = Vec::new();
+    for k in 0..sig.size() {
+      let mut val: f64 = 0.;
+      for i in 0..sig.size() {
+        val += sig.get(i as isize)*sig.get((i+k) as isize);
+      }
+      vals.push(val/(sig.size() as f64));
+    }
+    vals
+  }
+
+  #[test]
+  fn autocorrelation_matches_naive_power_of_two() {
+    let sig = PeriodicSignal::new(vec![1.,-1.,1.,1.,-1.,-1.,1.,-1.]);
+    let expected = autocorrelation_naive(&sig);
+    let actual = autocorrelation(
+      PeriodicSignal::new(sig.to_vector(0, sig.size() as isize - 1)));
+    assert_eq_floatvec!(expected, actual.to_vector(0, expected.len() as isize - 1), 1e-10);
+  }
+
+  #[test]
+  fn autocorrelation_matches_naive_non_power_of_two() {
+    let mut mls: MaximumLengthSequence<f64> =
+      MaximumLengthSequence::new_predefined(3, vec![true;3]);
+    mls.set_vals(-1., 1.);
+    let values = mls.to_vector();
+    let sig = PeriodicSignal::new(values.clone());
+    let expected = autocorrelation_naive(&sig);
+    let actual = autocorrelation(PeriodicSignal::new(values));
+    assert_eq_floatvec!(expected, actual.to_vector(0, expected.len() as isize - 1), 1e-10);
+  }
+
+  fn crosscorrelation_naive(a: &PeriodicSignal<f64>, b: &PeriodicSignal<f64>) -> Vec<f64> {
+    let mut vals: Vec<f64> = Vec::new();
+    for k in 0..a.size() {
+      let mut val: f64 = 0.;
+      for i in 0..a.size() {
+        val += a.get(i as isize)*b.get((i+k) as isize);
+      }
+      vals.push(val/(a.size() as f64));
+    }
+    vals
+  }
+
+  #[test]
+  fn crosscorrelation_matches_naive() {
+    let a = PeriodicSignal::new(vec![1.,-1.,1.,1.,-1.,-1.,1.,-1.]);
+    let b = PeriodicSignal::new(vec![-1.,1.,1.,-1.,1.,1.,-1.,1.]);
+    let expected = crosscorrelation_naive(&a, &b);
+    let actual = crosscorrelation(
+      PeriodicSignal::new(a.to_vector(0, a.size() as isize - 1)),
+      PeriodicSignal::new(b.to_vector(0, b.size() as isize - 1)));
+    assert_eq_floatvec!(expected, actual.to_vector(0, expected.len() as isize - 1), 1e-10);
+  }
+
+  #[test]
+  fn crosscorrelation_matches_naive_non_power_of_two() {
+    /* Two distinct period-7 (non-power-of-two) MLS sequences, from
+       the same tap polynomial but different initial states, so
+       `a != b`: */
+    let mut a_mls: MaximumLengthSequence<f64> =
+      MaximumLengthSequence::new_predefined(3, vec![true,true,true]);
+    a_mls.set_vals(-1., 1.);
+    let mut b_mls: MaximumLengthSequence<f64> =
+      MaximumLengthSequence::new_predefined(3, vec![true,false,false]);
+    b_mls.set_vals(-1., 1.);
+    let a = PeriodicSignal::new(a_mls.to_vector());
+    let b = PeriodicSignal::new(b_mls.to_vector());
+    let expected = crosscorrelation_naive(&a, &b);
+    let actual = crosscorrelation(
+      PeriodicSignal::new(a.to_vector(0, a.size() as isize - 1)),
+      PeriodicSignal::new(b.to_vector(0, b.size() as isize - 1)));
+    assert_eq_floatvec!(expected, actual.to_vector(0, expected.len() as isize - 1), 1e-10);
+  }
+
+  #[test]
+  #[should_panic(expected = "crosscorrelation requires both signals to share a period")]
+  fn crosscorrelation_requires_equal_sizes() {
+    crosscorrelation(
+      PeriodicSignal::new(vec![1.,2.,3.]),
+      PeriodicSignal::new(vec![1.,2.]));
+  }
+
+  #[test]
+  fn levinson_durbin_ar1_process() {
+    /* Autocorrelation of an AR(1) process x[n] = 0.5*x[n-1] + e[n]
+       is r[k] = 0.5^|k|; the order-2 LPC should recover a1=0.5, a2=0: */
+    let r = vec![1., 0.5, 0.25];
+    assert_eq_floatvec!(vec![0.5, 0.], levinson_durbin(&r, 2), 1e-12);
+  }
+
+  #[test]
+  fn levinson_durbin_stops_early_on_zero_error() {
+    let r = vec![1., 1., 1.];
+    assert_eq_floatvec!(vec![1.], levinson_durbin(&r, 2), 1e-12);
+  }
+
+  #[test]
+  fn levinson_durbin_stops_early_on_zero_r0() {
+    let r = vec![0., 1., 1.];
+    let empty: Vec<f64> = Vec::new();
+    assert_eq_floatvec!(empty, levinson_durbin(&r, 2), 1e-12);
+  }
+
+  #[test]
+  #[should_panic(expected = "levinson_durbin requires an autocorrelation sequence r[0..=p]")]
+  fn levinson_durbin_requires_matching_length() {
+    levinson_durbin(&vec![1., 0.5], 2);
+  }
 }
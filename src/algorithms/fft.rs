@@ -0,0 +1,141 @@
+extern crate num;
+
+use self::num::complex::Complex;
+use std::f64::consts::PI;
+
+/**
+  Computes the discrete Fourier transform of `x` using an iterative
+  radix-2 Cooley-Tukey algorithm. The length of `x` must be a power
+  of two.
+*/
+#[allow(dead_code)]
+pub fn fft(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
+  transform(x, false)
+}
+
+/**
+  Computes the inverse discrete Fourier transform of `x` using the
+  same radix-2 algorithm as `fft`. The length of `x` must be a power
+  of two.
+*/
+#[allow(dead_code)]
+pub fn ifft(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
+  transform(x, true)
+}
+
+/**
+  Shared implementation for `fft` and `ifft`: bit-reversal permutation
+  followed by log2(len) butterfly stages, negating the twiddle angle
+  and normalizing by `1/len` for the inverse direction.
+*/
+fn transform(x: &[Complex<f64>], inverse: bool) -> Vec<Complex<f64>> {
+  let n = x.len();
+  assert!(n.is_power_of_two(),
+    "fft/ifft require a power-of-two length, got {}", n);
+  let mut a = bit_reverse_permutation(x);
+  /* Iterate over the butterfly stages, doubling the block length
+     each time: */
+  let mut len = 2;
+  while len <= n {
+    let angle = if inverse { 2.*PI/(len as f64) } else { -2.*PI/(len as f64) };
+    let wlen = Complex::new(angle.cos(), angle.sin());
+    let mut i = 0;
+    while i < n {
+      let mut w = Complex::new(1., 0.);
+      for j in 0..(len/2) {
+        let u = a[i+j];
+        let v = a[i+j+len/2]*w;
+        a[i+j] = u+v;
+        a[i+j+len/2] = u-v;
+        w = w*wlen;
+      }
+      i += len;
+    }
+    len <<= 1;
+  }
+  if inverse {
+    for v in a.iter_mut() {
+      *v = *v / (n as f64);
+    }
+  }
+  a
+}
+
+/**
+  Returns a copy of `x` with elements reordered to bit-reversed index
+  order, the standard first step of an iterative Cooley-Tukey FFT.
+*/
+fn bit_reverse_permutation(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
+  let n = x.len();
+  let bits = if n > 1 { (63 - (n as u64).leading_zeros()) as u32 } else { 0 };
+  let mut out = x.to_vec();
+  for i in 0..n {
+    let r = reverse_bits(i as u32, bits) as usize;
+    if r > i {
+      out.swap(i, r);
+    }
+  }
+  out
+}
+
+/**
+  Reverses the lowest `bits` bits of `x`.
+*/
+fn reverse_bits(x: u32, bits: u32) -> u32 {
+  let mut x = x;
+  let mut r = 0u32;
+  for _ in 0..bits {
+    r = (r << 1) | (x & 1);
+    x >>= 1;
+  }
+  r
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{fft, ifft};
+  use super::num::complex::Complex;
+
+  fn c(re: f64) -> Complex<f64> {
+    Complex::new(re, 0.)
+  }
+
+  #[test]
+  #[should_panic(expected = "fft/ifft require a power-of-two length")]
+  fn fft_rejects_non_power_of_two() {
+    fft(&[c(1.), c(2.), c(3.)]);
+  }
+
+  #[test]
+  fn fft_of_impulse_is_flat() {
+    /* The DFT of a unit impulse is constant across all bins: */
+    let x = vec![c(1.), c(0.), c(0.), c(0.)];
+    let spectrum = fft(&x);
+    for bin in spectrum {
+      assert!((bin.re - 1.).abs() < 1e-12);
+      assert!(bin.im.abs() < 1e-12);
+    }
+  }
+
+  #[test]
+  fn fft_then_ifft_is_identity() {
+    let x = vec![c(1.), c(2.), c(3.), c(4.), c(5.), c(6.), c(7.), c(8.)];
+    let roundtrip = ifft(&fft(&x));
+    for (a, b) in x.iter().zip(roundtrip.iter()) {
+      assert!((a.re - b.re).abs() < 1e-10);
+      assert!((a.im - b.im).abs() < 1e-10);
+    }
+  }
+
+  #[test]
+  fn fft_of_constant_signal() {
+    /* A constant signal has all its energy in the DC bin: */
+    let x = vec![c(2.), c(2.), c(2.), c(2.)];
+    let spectrum = fft(&x);
+    assert!((spectrum[0].re - 8.).abs() < 1e-12);
+    for bin in spectrum.iter().skip(1) {
+      assert!(bin.re.abs() < 1e-12);
+      assert!(bin.im.abs() < 1e-12);
+    }
+  }
+}
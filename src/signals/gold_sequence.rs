@@ -0,0 +1,207 @@
+extern crate num;
+
+use signals::maximum_length_sequence::MaximumLengthSequence;
+
+/**
+  Models a Gold-code generator: the family of `2^m + 1` sequences
+  built from a preferred pair of degree-`m` maximum length sequences
+  `u` and `v`, namely `u`, `v`, and `u XOR shift(v, k)` for `k` in
+  `0..(2^m-1)`. Members of this family have pairwise cross-correlation
+  bounded by the three-valued Gold set, which single m-sequences
+  cannot guarantee.
+  Can be used with any type that implements num::traits::Num and
+  Copy.
+*/
+#[allow(dead_code)]
+pub struct GoldSequence<T> {
+  u: Vec<u8>,
+  v: Vec<u8>,
+  val_false: T,
+  val_true: T
+}
+
+impl<T: num::traits::Num + Copy> GoldSequence<T> {
+  /**
+    Creates a new instance from two base m-sequence polynomials and
+    their initial states, following the same coefficient convention
+    as `MaximumLengthSequence::new`. Both polynomials must have the
+    same degree, i.e. `state_a.len() == state_b.len()`.
+  */
+  #[allow(dead_code)]
+  pub fn new(poly_a: Vec<bool>, poly_b: Vec<bool>,
+    state_a: Vec<bool>, state_b: Vec<bool>) -> GoldSequence<T> {
+    assert_eq!(state_a.len(), state_b.len(),
+      "the two base m-sequences must have the same degree");
+    let mls_a: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(poly_a, state_a);
+    let mls_b: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(poly_b, state_b);
+    GoldSequence::<T> {
+      u: mls_a.to_vector(),
+      v: mls_b.to_vector(),
+      val_false: T::zero(),
+      val_true: T::one()
+    }
+  }
+
+  /**
+    Creates a new instance using one of the shipped preferred
+    polynomial pairs for orders 5, 6 and 7.
+  */
+  #[allow(dead_code)]
+  pub fn new_preferred_pair(order: u8, state_a: Vec<bool>,
+    state_b: Vec<bool>) -> GoldSequence<T> {
+    let (poly_a, poly_b) = GoldSequence::<T>::preferred_pair(order);
+    GoldSequence::<T>::new(poly_a, poly_b, state_a, state_b)
+  }
+
+  /**
+    Returns the preferred pair of tap polynomials for the given
+    order, using the coefficient convention of
+    `MaximumLengthSequence::new_predefined`.
+    Used polynomial pairs:
+      order 5: p1(x) = x^5 + x^2 + 1
+               p2(x) = x^5 + x^4 + x^3 + x^2 + 1
+      order 6: p1(x) = x^6 + x + 1
+               p2(x) = x^6 + x^5 + x^2 + x + 1
+      order 7: p1(x) = x^7 + x^3 + 1
+               p2(x) = x^7 + x^3 + x^2 + x + 1
+  */
+  fn preferred_pair(order: u8) -> (Vec<bool>, Vec<bool>) {
+    match order {
+      5 => (
+        vec![false,true,false,false],
+        vec![false,true,true,true]
+      ),
+      6 => (
+        vec![true,false,false,false,false],
+        vec![true,true,false,false,true]
+      ),
+      7 => (
+        vec![false,false,true,false,false,false],
+        vec![true,true,true,false,false,false]
+      ),
+      _ => panic!("Sorry, no preferred pair for order {}, yet.", order)
+    }
+  }
+
+  /**
+    Sets the two values the sequences can be.
+  */
+  #[allow(dead_code)]
+  pub fn set_vals(&mut self, val_false: T, val_true: T) {
+    self.val_false = val_false;
+    self.val_true = val_true;
+  }
+
+  /**
+    Returns the number of sequences in the family, `2^m + 1`.
+  */
+  #[allow(dead_code)]
+  pub fn len(&self) -> usize {
+    self.u.len() + 2
+  }
+
+  /**
+    Returns the member at the given index as a vector: index `0` is
+    `u`, index `1` is `v`, and indices `2..len()` are
+    `u XOR shift(v, index-2)`.
+  */
+  #[allow(dead_code)]
+  pub fn select(&self, index: usize) -> Vec<T> {
+    assert!(index < self.len(), "gold sequence index out of range");
+    let n = self.u.len();
+    let bits: Vec<u8> = if index == 0 {
+      self.u.clone()
+    } else if index == 1 {
+      self.v.clone()
+    } else {
+      let k = index - 2;
+      (0..n).map(|i| self.u[i] ^ self.v[(i+k) % n]).collect()
+    };
+    bits.iter().map(|&b|
+      if b != 0 { self.val_true } else { self.val_false }).collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::GoldSequence;
+  use signals::maximum_length_sequence::MaximumLengthSequence;
+  use signals::periodic_signal::PeriodicSignal;
+
+  #[test]
+  fn gold_sequence_len() {
+    let gold: GoldSequence<u8> = GoldSequence::new(
+      vec![true,false], vec![true,false],
+      vec![true,true,true], vec![true,true,true]);
+    /* order 3: family has 2^3+1 = 9 members: */
+    assert_eq!(9, gold.len());
+  }
+
+  #[test]
+  fn gold_sequence_select_u_and_v() {
+    let poly_a = vec![true,false];
+    let poly_b = vec![true,false];
+    let state_a = vec![false,true,true];
+    let state_b = vec![true,false,false];
+    let gold: GoldSequence<u8> = GoldSequence::new(
+      poly_a.clone(), poly_b.clone(), state_a.clone(), state_b.clone());
+    let u: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(poly_a, state_a);
+    let v: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(poly_b, state_b);
+    assert_eq!(u.to_vector(), gold.select(0));
+    assert_eq!(v.to_vector(), gold.select(1));
+  }
+
+  #[test]
+  fn gold_sequence_xor_members_span_one_period() {
+    let gold: GoldSequence<u8> = GoldSequence::new(
+      vec![true,false], vec![true,false],
+      vec![true,true,true], vec![false,true,true]);
+    /* Every XOR member holds one full period of the order-3 base
+       sequences, i.e. 2^3-1 = 7 values: */
+    for i in 2..gold.len() {
+      let member = gold.select(i);
+      let period_signal: PeriodicSignal<u8> =
+        PeriodicSignal::new(member);
+      assert_eq!(7, period_signal.size());
+    }
+  }
+
+  #[test]
+  fn gold_sequence_set_vals() {
+    let mut gold: GoldSequence<i8> = GoldSequence::new(
+      vec![true,false], vec![true,false],
+      vec![true,true,true], vec![false,true,true]);
+    gold.set_vals(-1, 1);
+    for v in gold.select(0) {
+      assert!(v == -1 || v == 1);
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "the two base m-sequences must have the same degree")]
+  fn gold_sequence_requires_matching_degree() {
+    let _: GoldSequence<u8> = GoldSequence::new(
+      vec![true,false], vec![true,false,false],
+      vec![true,true,true], vec![true,true,true,true]);
+  }
+
+  #[test]
+  #[should_panic(expected = "Sorry, no preferred pair for order 3, yet.")]
+  fn gold_sequence_preferred_pair_only_covers_5_6_7() {
+    let _: GoldSequence<u8> = GoldSequence::new_preferred_pair(
+      3, vec![true,true,true], vec![true,true,true]);
+  }
+
+  #[test]
+  fn gold_sequence_preferred_pair_order5() {
+    let gold: GoldSequence<u8> = GoldSequence::new_preferred_pair(
+      5, vec![true;5], vec![true;5]);
+    /* order 5: family has 2^5+1 = 33 members, each of period 31: */
+    assert_eq!(33, gold.len());
+    assert_eq!(31, gold.select(0).len());
+  }
+}
@@ -1,10 +1,21 @@
 extern crate num;
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use signals::periodic_signal::PeriodicSignal;
+
+thread_local! {
+  /* Caches primitive polynomials discovered by `new_maximal`, keyed
+     by order, so repeated calls don't re-run the search: */
+  static MAXIMAL_POLYNOMIAL_CACHE: RefCell<HashMap<u8, Vec<bool>>> =
+    RefCell::new(HashMap::new());
+}
+
 /**
   Models a maximum length sequence generator.
 */
 #[allow(dead_code)]
-struct MaximumLengthSequence<T> {
+pub struct MaximumLengthSequence<T> {
   coefficients: Vec<bool>,
   state: Vec<bool>,
   val_false: T,
@@ -74,7 +85,75 @@ impl<T: num::traits::Num + Copy> MaximumLengthSequence<T> {
       _ => panic!("Sorry, no polynom for order {}, yet.", order)
     }
   }
-  
+
+  /**
+    Creates a new maximum-length generator for any `order` by
+    searching for a primitive tap polynomial at runtime, instead of
+    being limited to the hard-coded orders of `new_predefined`.
+    Discovered polynomials are memoized per order. `max_candidates`
+    bounds how many candidates are tried; if none of them are
+    primitive, an `Err` is returned instead of panicking. An `order`
+    of 0, or one not below the bit width of `usize`, also yields an
+    `Err`.
+  */
+  #[allow(dead_code)]
+  pub fn new_maximal(order: u8, state: Vec<bool>, max_candidates: usize)
+    -> Result<MaximumLengthSequence<T>, String> {
+    let cached = MAXIMAL_POLYNOMIAL_CACHE.with(|cache|
+      cache.borrow().get(&order).cloned());
+    let coefficients = match cached {
+      Some(poly) => poly,
+      None => {
+        let poly = MaximumLengthSequence::<T>::search_primitive_polynomial(
+          order, max_candidates)?;
+        MAXIMAL_POLYNOMIAL_CACHE.with(|cache|
+          cache.borrow_mut().insert(order, poly.clone()));
+        poly
+      }
+    };
+    Ok(MaximumLengthSequence::<T>::new(coefficients, state))
+  }
+
+  /**
+    Searches the first `max_candidates` coefficient vectors of length
+    `order-1` for one that makes a full-period trial sequence
+    maximal, returning it, or an `Err` if none of them are.
+  */
+  fn search_primitive_polynomial(order: u8, max_candidates: usize)
+    -> Result<Vec<bool>, String> {
+    /* `1usize << order` (and `<< tap_count`) would overflow once order
+       reaches the bit width of usize, panicking in debug builds and
+       silently wrapping to a bogus candidate/period count in release;
+       bail out with an `Err` before doing any shifting. Order 0 has no
+       taps to search either, so it's rejected the same way. */
+    let usize_bits = (::std::mem::size_of::<usize>()*8) as u8;
+    if order == 0 || order >= usize_bits {
+      return Err(format!("order {} is out of range for a primitive \
+        polynomial search (must be between 1 and {})", order, usize_bits-1));
+    }
+    let tap_count = (order-1) as u32;
+    let total_candidates = 1usize << tap_count;
+    let period = (1usize << order)-1;
+    let candidates = if max_candidates < total_candidates {
+      max_candidates
+    } else {
+      total_candidates
+    };
+    for candidate in 0..candidates {
+      let coefficients: Vec<bool> = (0..tap_count)
+        .map(|bit| (candidate >> bit) & 1 == 1)
+        .collect();
+      let trial: MaximumLengthSequence<u8> = MaximumLengthSequence::new(
+        coefficients.clone(), vec![true; order as usize]);
+      let sequence = PeriodicSignal::new(trial.to_vector());
+      if sequence.period() == period {
+        return Ok(coefficients);
+      }
+    }
+    Err(format!("no primitive polynomial of order {} found within the \
+      first {} candidates", order, candidates))
+  }
+
   /**
     Sets the two values the sequence can be.
   */
@@ -104,32 +183,29 @@ impl<T: num::traits::Num + Copy> MaximumLengthSequence<T> {
   }
 
   /**
-    Returns one period as a vector, does not change
-    the internal state.
+    Returns one period as a vector. Consumes `self` since it's built
+    by collecting `period_iter`.
   */
   #[allow(dead_code)]
   pub fn to_vector(self) -> Vec<T> {
-    /* Create a temporary internal state */
-    let mut state: Vec<bool> = self.state;
-    /* Create a vector that we return later: */
-    let mut x: Vec<T> = Vec::new();
-    /* Iterate over the period: */
-    for _ in 0..(2usize.pow(state.len() as u32)-1) {
-      /* Add the value: */
-      if state[state.len()-1] {
-        x.push(self.val_true);
-      } else {
-        x.push(self.val_false);
-      }
-      /* And set the new state: */
-      state = MaximumLengthSequence::<T>::next_state(&state,
-        &self.coefficients);
+    self.period_iter().collect()
+  }
+
+  /**
+    Returns an iterator bounded to exactly one period, i.e.
+    `2^n - 1` items where `n` is the generator's order. Unlike the
+    unbounded `Iterator` implementation, this adaptor does return
+    `None` once the period is exhausted.
+  */
+  #[allow(dead_code)]
+  pub fn period_iter(self) -> PeriodIter<T> {
+    let period = (2usize.pow(self.state.len() as u32))-1;
+    PeriodIter {
+      mls: self,
+      remaining: period
     }
-    /* Make the vector immutable and return it: */
-    let x = x;
-    x
   }
-  
+
   /**
     Returns the next state for the given one.
   */
@@ -155,6 +231,54 @@ impl<T: num::traits::Num + Copy> MaximumLengthSequence<T> {
   }
 }
 
+impl<T: num::traits::Num + Copy> Iterator for MaximumLengthSequence<T> {
+  type Item = T;
+
+  /**
+    Yields the next value of the sequence, same as the inherent
+    `next` method. The stream is infinite/periodic and never returns
+    `None`, so this composes with `take`, `zip`, `map` and `collect`
+    without materializing a whole period first.
+  */
+  fn next(&mut self) -> Option<T> {
+    Some(MaximumLengthSequence::next(self))
+  }
+
+  /**
+    The stream never ends, so there's no known upper bound.
+  */
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (usize::MAX, None)
+  }
+}
+
+/**
+  Iterator adaptor yielding exactly one period of a
+  `MaximumLengthSequence`, returned by `period_iter`.
+*/
+#[allow(dead_code)]
+pub struct PeriodIter<T> {
+  mls: MaximumLengthSequence<T>,
+  remaining: usize
+}
+
+impl<T: num::traits::Num + Copy> Iterator for PeriodIter<T> {
+  type Item = T;
+
+  fn next(&mut self) -> Option<T> {
+    if self.remaining == 0 {
+      None
+    } else {
+      self.remaining -= 1;
+      Some(MaximumLengthSequence::next(&mut self.mls))
+    }
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining, Some(self.remaining))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::MaximumLengthSequence;
@@ -313,4 +437,116 @@ mod tests {
       assert_eq!((2u32.pow((i+1) as u32) as usize)-1, y.period());
     }
   }
+
+  #[test]
+  fn maximum_length_sequence_new_maximal_finds_full_period() {
+    for order in 2u8..8 {
+      let x: MaximumLengthSequence<u8> =
+        MaximumLengthSequence::new_maximal(order, vec![true; order as usize], 1 << 20)
+          .unwrap();
+      let v = x.to_vector();
+      let period = (2u32.pow(order as u32) as usize)-1;
+      assert_eq!(period, v.len());
+      let y: PeriodicSignal<u8> = PeriodicSignal::new(v);
+      assert_eq!(period, y.period());
+    }
+  }
+
+  #[test]
+  fn maximum_length_sequence_new_maximal_is_memoized() {
+    let a: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new_maximal(5, vec![true;5], 1 << 20).unwrap();
+    let b: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new_maximal(5, vec![true;5], 1 << 20).unwrap();
+    assert_eq!(a.to_vector(), b.to_vector());
+  }
+
+  #[test]
+  fn maximum_length_sequence_new_maximal_bounded_search_fails() {
+    /* The trivial first candidate (all coefficients false, i.e.
+       p(x) = x^4 + 1) is not primitive, so a search bounded to a
+       single candidate must fail rather than panic: */
+    let result: Result<MaximumLengthSequence<u8>, String> =
+      MaximumLengthSequence::new_maximal(4, vec![true;4], 1);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn maximum_length_sequence_new_maximal_rejects_order_too_large_for_usize() {
+    /* `order` this large would overflow the `1usize << order` shifts
+       used during the search; this must return an `Err` instead of
+       panicking or silently wrapping: */
+    let usize_bits = (::std::mem::size_of::<usize>()*8) as u8;
+    let result: Result<MaximumLengthSequence<u8>, String> =
+      MaximumLengthSequence::new_maximal(usize_bits, vec![true; usize_bits as usize], 1);
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn maximum_length_sequence_iterator_take_matches_to_vector() {
+    /* x^3 + x + 1; init state: 0-1-1 */
+    let x1: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(vec![true,false],
+        vec![false,true,true]);
+    let x2: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(vec![true,false],
+        vec![false,true,true]);
+    let expected = x1.to_vector();
+    let collected: Vec<u8> = x2.take(expected.len()).collect();
+    assert_eq!(expected, collected);
+  }
+
+  #[test]
+  fn maximum_length_sequence_iterator_repeats_past_one_period() {
+    /* x^3 + x + 1; init state: 0-1-1 */
+    let x: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(vec![true,false],
+        vec![false,true,true]);
+    /* Two full periods back to back, confirming the stream never
+       stops at `None`: */
+    let collected: Vec<u8> = x.take(14).collect();
+    assert_eq!(vec![1,1,0,0,1,0,1,1,1,0,0,1,0,1], collected);
+  }
+
+  #[test]
+  fn maximum_length_sequence_iterator_composes_with_map_and_zip() {
+    let x: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(vec![true,false],
+        vec![false,true,true]);
+    let y: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(vec![true,false],
+        vec![true,false,false]);
+    let combined: Vec<(f32, u8)> = x.map(|v| v as f32).zip(y).take(7).collect();
+    assert_eq!(
+      vec![(1.,0),(1.,0),(0.,1),(0.,0),(1.,1),(0.,1),(1.,1)],
+      combined);
+  }
+
+  #[test]
+  fn maximum_length_sequence_size_hint_is_unbounded() {
+    let x: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(vec![true,false],
+        vec![false,true,true]);
+    assert_eq!((usize::MAX, None), x.size_hint());
+  }
+
+  #[test]
+  fn maximum_length_sequence_period_iter_stops_after_one_period() {
+    let x: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(vec![true,false],
+        vec![false,true,true]);
+    let collected: Vec<u8> = x.period_iter().collect();
+    assert_eq!(vec![1,1,0,0,1,0,1], collected);
+  }
+
+  #[test]
+  fn maximum_length_sequence_period_iter_size_hint_counts_down() {
+    let x: MaximumLengthSequence<u8> =
+      MaximumLengthSequence::new(vec![true,false],
+        vec![false,true,true]);
+    let mut period_iter = x.period_iter();
+    assert_eq!((7, Some(7)), period_iter.size_hint());
+    period_iter.next();
+    assert_eq!((6, Some(6)), period_iter.size_hint());
+  }
 }
@@ -1,5 +1,8 @@
 extern crate num;
 
+use std::iter::FromIterator;
+use signals::signal::Signal;
+
 /**
   Models an infinite, periodic signal.
   Can be used with any type that implements
@@ -54,26 +57,6 @@ impl<T: num::traits::Num + Clone> PeriodicSignal<T> {
     }
   }
   
-  /**
-    Returns a vector of signal values, starting with index start,
-    ending with index end.
-    TODO: Share implementation with ZeroPaddedSignal.
-  */
-  #[allow(dead_code)]
-  pub fn to_vector(&self, start: isize, end: isize) -> Vec<T> {
-    /* Create an empty vector: */
-    let mut x: Vec<T> = Vec::new();
-    /* Loop through the given range: */
-    for i in start..(end+1) {
-      /* Add the values to the vector: */
-      x.push(self.get(i));
-    }
-    /* Make the vector immutable: */
-    let x = x;
-    /* Return the vector: */
-    x
-  }
-  
   /**
     Calculates the smallest period of the signal.
   */
@@ -124,9 +107,41 @@ impl<T: num::traits::Num + Clone> PeriodicSignal<T> {
   }*/
 }
 
+impl<T: num::traits::Num + Clone> Signal<T> for PeriodicSignal<T> {
+  fn get(&self, idx: isize) -> T {
+    self.get(idx)
+  }
+  fn size(&self) -> usize {
+    self.size()
+  }
+}
+
+impl<T: num::traits::Num + Clone> FromIterator<T> for PeriodicSignal<T> {
+  /**
+    Builds a signal directly from an iterator of one period's worth
+    of values, e.g. `(0..512).map(|n| (n as f64).sin()).collect()`.
+  */
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> PeriodicSignal<T> {
+    PeriodicSignal::new(iter.into_iter().collect())
+  }
+}
+
+impl<T: num::traits::Num + Clone> IntoIterator for PeriodicSignal<T> {
+  type Item = T;
+  type IntoIter = ::std::vec::IntoIter<T>;
+
+  /**
+    Iterates over one full period's worth of values, in order.
+  */
+  fn into_iter(self) -> Self::IntoIter {
+    self.values.into_iter()
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::PeriodicSignal;
+  use super::Signal;
 
   #[test]
   fn size() {
@@ -169,4 +184,44 @@ mod tests {
     assert_eq!(2, x2.period());
     assert_eq!(4, x3.period());
   }
+  #[test]
+  fn energy() {
+    /* Create test signal: */
+    let x1: PeriodicSignal<u32> = PeriodicSignal::new(vec![3,4]);
+    /* Test `energy` method: */
+    assert_eq!(25, x1.energy());
+  }
+  #[test]
+  fn map() {
+    /* Create test signal: */
+    let x1: PeriodicSignal<u32> = PeriodicSignal::new(vec![1,2,3]);
+    /* Test `map` method: */
+    assert_eq!(vec![2,4,6], x1.map(|v| v*2));
+  }
+  #[test]
+  fn convolve() {
+    /* Create test signal: */
+    let x1: PeriodicSignal<f64> = PeriodicSignal::new(vec![1.,1.,1.]);
+    /* Test `convolve` method: every output wraps onto the same
+       all-ones period, so every output sample sees the full weight
+       of `other`: */
+    assert_eq_floatvec!(
+      vec![3.,3.,3.,3.],
+      x1.convolve(&vec![1.,2.]),
+      1e-15
+    );
+  }
+  #[test]
+  fn from_iterator() {
+    /* Test `FromIterator`: */
+    let x1: PeriodicSignal<f64> = (0..4).map(|n| n as f64).collect();
+    assert_eq!(vec![0.,1.,2.,3.], x1.to_vector(0,3));
+  }
+  #[test]
+  fn into_iterator() {
+    /* Test `IntoIterator`: */
+    let x1: PeriodicSignal<u8> = PeriodicSignal::new(vec![1,2,3,4]);
+    let collected: Vec<u8> = x1.into_iter().collect();
+    assert_eq!(vec![1,2,3,4], collected);
+  }
 }
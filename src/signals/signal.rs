@@ -0,0 +1,123 @@
+extern crate num;
+
+/**
+  Common interface for signal representations that can be indexed by
+  an integer index and report a size, such as `ZeroPaddedSignal` and
+  `PeriodicSignal`. `get` and `size` are the only required methods;
+  the rest are provided in terms of them.
+*/
+pub trait Signal<T: num::traits::Num + Clone> {
+  /**
+    Returns the value at the given index.
+  */
+  fn get(&self, idx: isize) -> T;
+
+  /**
+    Returns the number of initialized values.
+  */
+  fn size(&self) -> usize;
+
+  /**
+    Returns a vector of signal values, starting with index start,
+    ending with index end.
+  */
+  fn to_vector(&self, start: isize, end: isize) -> Vec<T> {
+    let mut x: Vec<T> = Vec::new();
+    for i in start..(end+1) {
+      x.push(self.get(i));
+    }
+    x
+  }
+
+  /**
+    Returns the sum of the squared values over `0..size()`.
+  */
+  fn energy(&self) -> T {
+    let mut e: T = T::zero();
+    for i in 0..self.size() {
+      let v = self.get(i as isize);
+      e = e + v.clone()*v;
+    }
+    e
+  }
+
+  /**
+    Applies `f` to every value over `0..size()`, returning a plain
+    vector of the results.
+  */
+  fn map<U, F: Fn(T) -> U>(&self, f: F) -> Vec<U> {
+    let mut x: Vec<U> = Vec::new();
+    for i in 0..self.size() {
+      x.push(f(self.get(i as isize)));
+    }
+    x
+  }
+
+  /**
+    Convolves this signal with `other`, returning
+    `y[n] = sum_k self.get(n-k) * other[k]` for `n` in
+    `0..(size()+other.len()-1)`. Values are read through `get`, so
+    indices outside `0..size()` follow the signal's own semantics
+    (zero for `ZeroPaddedSignal`, wrap-around for `PeriodicSignal`).
+  */
+  fn convolve(&self, other: &Vec<T>) -> Vec<T> {
+    let len = self.size() + other.len() - 1;
+    let mut y: Vec<T> = Vec::new();
+    for n in 0..len {
+      let mut val: T = T::zero();
+      for k in 0..other.len() {
+        val = val + self.get((n as isize)-(k as isize))*other[k].clone();
+      }
+      y.push(val);
+    }
+    y
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Signal;
+
+  /* Minimal zero-padded implementation, just enough to exercise the
+     trait's default methods in isolation: */
+  struct TestSignal {
+    values: Vec<i32>
+  }
+
+  impl Signal<i32> for TestSignal {
+    fn get(&self, idx: isize) -> i32 {
+      if idx < 0 {
+        0
+      } else {
+        self.values.get(idx as usize).cloned().unwrap_or(0)
+      }
+    }
+    fn size(&self) -> usize {
+      self.values.len()
+    }
+  }
+
+  #[test]
+  fn default_to_vector() {
+    let s = TestSignal { values: vec![1,2,3] };
+    assert_eq!(vec![0,1,2,3,0], s.to_vector(-1,3));
+  }
+
+  #[test]
+  fn default_energy() {
+    let s = TestSignal { values: vec![3,4] };
+    assert_eq!(25, s.energy());
+  }
+
+  #[test]
+  fn default_map() {
+    let s = TestSignal { values: vec![1,2,3] };
+    assert_eq!(vec![2,4,6], s.map(|v| v*2));
+  }
+
+  #[test]
+  fn default_convolve() {
+    let s = TestSignal { values: vec![1,1,1] };
+    assert_eq!(vec![1,3,3,2], s.convolve(&vec![1,2]));
+  }
+}
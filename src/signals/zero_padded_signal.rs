@@ -1,5 +1,8 @@
 extern crate num;
 
+use std::iter::FromIterator;
+use signals::signal::Signal;
+
 /**
   Models an infinite signal, negative indices return
   always zero (to ensure causality of the signal),
@@ -55,25 +58,6 @@ impl<T: num::traits::Num + Clone> ZeroPaddedSignal<T> {
     }
   }
   
-  /**
-    Returns a vector of signal values, starting with index start,
-    ending with index end.
-  */
-  #[allow(dead_code)]
-  pub fn to_vector(&self, start: isize, end: isize) -> Vec<T> {
-    /* Create an empty vector: */
-    let mut x: Vec<T> = Vec::new();
-    /* Loop through the given range: */
-    for i in start..(end+1) {
-      /* Add the values to the vector: */
-      x.push(self.get(i));
-    }
-    /* Make the vector immutable: */
-    let x = x;
-    /* Return the vector: */
-    x
-  }
-  
   /**
     Creates a new signal by doing Linear Prediction using
     the given coefficients.
@@ -104,6 +88,48 @@ impl<T: num::traits::Num + Clone> ZeroPaddedSignal<T> {
     x
   }
 
+  /**
+    Applies the linear constant-coefficient difference equation
+    `y[n] = (sum_k b[k]*x[n-k] - sum_{k>=1} a[k]*y[n-k]) / a[0]`,
+    with `self` as the input `x`, `b` the feed-forward (numerator)
+    taps and `a` the feedback (denominator) taps, `a[0]` being the
+    normalizing term. Negative indices of `x` fall back to the
+    zero-padded `get` semantics, and of `y` to zero, both giving the
+    correct causal boundary behavior. The output has
+    `self.size() + b.len() - 1` values.
+  */
+  #[allow(dead_code)]
+  pub fn filter(&self, b: Vec<T>, a: Vec<T>) -> ZeroPaddedSignal<T> {
+    let size: usize = self.size() + b.len() - 1;
+    let mut y: Vec<T> = Vec::new();
+    for n in 0..size {
+      let mut val: T = T::zero();
+      /* Feed-forward part, weighting past (and current) input: */
+      for k in 0..b.len() {
+        let idx = (n as isize) - (k as isize);
+        val = val + b[k].clone()*self.get(idx);
+      }
+      /* Feedback part, weighting past output: */
+      for k in 1..a.len() {
+        let idx = (n as isize) - (k as isize);
+        if idx >= 0 {
+          val = val - a[k].clone()*y[idx as usize].clone();
+        }
+      }
+      y.push(val/a[0].clone());
+    }
+    ZeroPaddedSignal { values: y }
+  }
+
+  /**
+    Pure-FIR convenience for `filter`, i.e. the `a = [1]` case:
+    convolves `self` with `b`.
+  */
+  #[allow(dead_code)]
+  pub fn convolve(&self, b: Vec<T>) -> ZeroPaddedSignal<T> {
+    self.filter(b, vec![T::one()])
+  }
+
   /**
     Sets the signal value at the given index. If there's
     a gap between the last initialized index and the given
@@ -120,10 +146,42 @@ impl<T: num::traits::Num + Clone> ZeroPaddedSignal<T> {
   }
 }
 
+impl<T: num::traits::Num + Clone> Signal<T> for ZeroPaddedSignal<T> {
+  fn get(&self, idx: isize) -> T {
+    self.get(idx)
+  }
+  fn size(&self) -> usize {
+    self.size()
+  }
+}
+
+impl<T: num::traits::Num + Clone> FromIterator<T> for ZeroPaddedSignal<T> {
+  /**
+    Builds a signal directly from an iterator of values, e.g.
+    `(0..512).map(|n| (n as f64).sin()).collect()`.
+  */
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> ZeroPaddedSignal<T> {
+    ZeroPaddedSignal::new(iter.into_iter().collect())
+  }
+}
+
+impl<T: num::traits::Num + Clone> IntoIterator for ZeroPaddedSignal<T> {
+  type Item = T;
+  type IntoIter = ::std::vec::IntoIter<T>;
+
+  /**
+    Iterates over the initialized values, in order.
+  */
+  fn into_iter(self) -> Self::IntoIter {
+    self.values.into_iter()
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::ZeroPaddedSignal;
-  
+  use super::Signal;
+
   #[test]
   fn zero_padded_signal_size() {
     /* Create test signal: */
@@ -180,4 +238,65 @@ mod tests {
       1e-15
     );
   }
+  #[test]
+  fn zero_padded_signal_energy() {
+    /* Create test signal: */
+    let x1: ZeroPaddedSignal<u32> = ZeroPaddedSignal::new(vec![3,4]);
+    /* Test `energy` method: */
+    assert_eq!(25, x1.energy());
+  }
+  #[test]
+  fn zero_padded_signal_map() {
+    /* Create test signal: */
+    let x1: ZeroPaddedSignal<u32> = ZeroPaddedSignal::new(vec![1,2,3]);
+    /* Test `map` method: */
+    assert_eq!(vec![2,4,6], x1.map(|v| v*2));
+  }
+  #[test]
+  fn zero_padded_signal_convolve() {
+    /* Create test signal: */
+    let x1: ZeroPaddedSignal<f64> = ZeroPaddedSignal::new(vec![1.,1.,1.]);
+    /* Test `convolve` method: */
+    assert_eq_floatvec!(
+      vec![1.,3.,3.,2.],
+      x1.convolve(vec![1.,2.]).values,
+      1e-15
+    );
+  }
+  #[test]
+  fn zero_padded_signal_filter_fir_matches_convolve() {
+    /* Create test signal: */
+    let x1: ZeroPaddedSignal<f64> = ZeroPaddedSignal::new(vec![1.,1.,1.]);
+    /* `filter` with `a = [1]` is a pure FIR convolution: */
+    assert_eq_floatvec!(
+      x1.convolve(vec![1.,2.]).values,
+      x1.filter(vec![1.,2.], vec![1.]).values,
+      1e-15
+    );
+  }
+  #[test]
+  fn zero_padded_signal_filter_iir() {
+    /* Create test signal, a unit impulse: */
+    let x1: ZeroPaddedSignal<f64> = ZeroPaddedSignal::new(vec![1.]);
+    /* y[n] = x[n] - 0.5*y[n-1] is the impulse response of a simple
+       one-pole filter, which decays geometrically: */
+    assert_eq_floatvec!(
+      vec![1.,-0.5,0.25,-0.125],
+      x1.filter(vec![1.,0.,0.,0.], vec![1.,0.5]).values,
+      1e-15
+    );
+  }
+  #[test]
+  fn zero_padded_signal_from_iterator() {
+    /* Test `FromIterator`: */
+    let x1: ZeroPaddedSignal<f64> = (0..4).map(|n| n as f64).collect();
+    assert_eq!(vec![0.,1.,2.,3.], x1.values);
+  }
+  #[test]
+  fn zero_padded_signal_into_iterator() {
+    /* Test `IntoIterator`: */
+    let x1: ZeroPaddedSignal<u32> = ZeroPaddedSignal::new(vec![42,7,11]);
+    let collected: Vec<u32> = x1.into_iter().collect();
+    assert_eq!(vec![42,7,11], collected);
+  }
 }